@@ -0,0 +1,53 @@
+//! The failure side of a [`PResult`](crate::parser::PResult).
+//!
+//! A [`PFailure`] records *where* a parser gave up (`position`, an offset into the
+//! original input) and *what* it was looking for (`expected`, a set of human-readable
+//! descriptions). Fundamental parsers like `just`, `pident`, and `pnum` populate
+//! `expected` with the single token they wanted; combinators that choose between
+//! alternatives (`por`, `choice!`) merge the `expected` sets of every branch that got
+//! equally far, so the final error reads as "expected one of {...} at offset N"
+//! instead of surfacing whichever branch happened to run last.
+
+use std::collections::BTreeSet;
+
+/// A parse failure: how far the parser got, and what it wanted to see there.
+///
+/// `expected` is a set rather than a `Vec` so that merging two failures at the same
+/// `position` (see [`PFailure::merge`]) doesn't accumulate duplicate descriptions when
+/// the same alternative is tried from multiple branches of a `choice!` tree.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PFailure {
+    /// Offset into the original input at which this failure occurred.
+    pub position: usize,
+    /// Descriptions of what would have allowed the parse to continue, e.g. `"'A'"`,
+    /// `"\"xyz\""`, or `"digit"`.
+    pub expected: BTreeSet<String>,
+}
+
+impl PFailure {
+    /// Builds a failure at `position` expecting a single thing.
+    pub fn new(position: usize, expected: impl Into<String>) -> Self {
+        let mut set = BTreeSet::new();
+        set.insert(expected.into());
+        PFailure {
+            position,
+            expected: set,
+        }
+    }
+
+    /// Combines two failures into the one that represents more progress.
+    ///
+    /// Keeps the failure whose `position` is furthest into the input, since that
+    /// branch got closer to a successful parse. On a tie, merges the two `expected`
+    /// sets so the caller learns about every alternative that was tried at that point.
+    pub fn merge(self, other: PFailure) -> PFailure {
+        match self.position.cmp(&other.position) {
+            std::cmp::Ordering::Greater => self,
+            std::cmp::Ordering::Less => other,
+            std::cmp::Ordering::Equal => PFailure {
+                position: self.position,
+                expected: self.expected.into_iter().chain(other.expected).collect(),
+            },
+        }
+    }
+}
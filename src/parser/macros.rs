@@ -127,6 +127,13 @@ macro_rules! select {
 /// into a sequene parser using pseq. Optionally one can use `=>`
 /// along with a closure to map the parser result into a useful result.
 ///
+/// Every `wrap!`-ed sub-parser is run through [`parser::pad::lexeme`](crate::parser::pad::lexeme),
+/// so whitespace between tokens is skipped automatically: `sequence!((pnum()) > '+' > (pnum()))`
+/// parses `"1 + 2"` just as happily as `"1+2"`. Only the elements of the sequence are
+/// wrapped this way (a parenthesised composite element is wrapped as a whole), so no
+/// global `ws!`-style wrapper is needed; `choice!` and `select!` do not skip whitespace
+/// on their own.
+///
 /// See [`select!`] for details about passing parser versus literals.
 ///
 /// # Examples
@@ -134,7 +141,7 @@ macro_rules! select {
 /// ```
 /// use cypress::prelude::*;
 ///
-/// let input = "1+2".into_input();
+/// let input = "1 + 2".into_input();
 ///
 /// #[derive(PartialEq, Debug)]
 /// enum Expr {
@@ -166,15 +173,94 @@ macro_rules! sequence {
 
     (@chain $head:tt > $($tail:tt)+) => {
         $crate::parser::seq::pseq(
-            $crate::wrap!($head),
+            $crate::parser::pad::lexeme($crate::wrap!($head)),
             $crate::sequence!(@chain $($tail)+)
         )
     };
 
     (@chain $last:tt) => {
-        $crate::wrap!($last)
+        $crate::parser::pad::lexeme($crate::wrap!($last))
+    };
+
+}
+
+/// Macro for parsing a separated list of items.
+///
+/// Expands to [`parser::sep::sep_by`](crate::parser::sep::sep_by), accepting the same
+/// literal-or-`(parser)` forms as [`sequence!`]/[`select!`] via [`wrap!`]. The result
+/// is a `Vec` of the item values; separator values are discarded.
+///
+/// Like `sequence!`, both `item` and `sep` are run through
+/// [`parser::pad::lexeme`](crate::parser::pad::lexeme), so whitespace around the
+/// separator is tolerated: `separated!((pnum()), ',')` parses `"1, 2, 3"` just as
+/// happily as `"1,2,3"`.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+///
+/// let parser = separated!((pnum()), ',');
+///
+/// match parser.parse("1, 2, 3".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, vec![b'1', b'2', b'3']),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+#[macro_export]
+macro_rules! separated {
+    ($item:tt, $sep:tt) => {
+        $crate::parser::sep::sep_by(
+            $crate::parser::pad::lexeme($crate::wrap!($item)),
+            $crate::parser::pad::lexeme($crate::wrap!($sep)),
+            false,
+        )
     };
+}
 
+/// Macro for declaring named, possibly mutually-recursive parsers.
+///
+/// Combinator values built by `sequence!`/`choice!`/`select!` have anonymous types, so
+/// a parser can't otherwise refer to itself or to a sibling rule defined below it.
+/// `rule!` first creates a [`parser::recursive::Recursive`](crate::parser::recursive::Recursive)
+/// handle for every named rule, *then* fills in each body, so every rule can refer to
+/// every other rule (including itself) regardless of definition order. Reference a
+/// sibling rule from within a body with `name.clone()`, since `Recursive` handles are
+/// `Rc`-backed and cheap to clone.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Expr {
+///     Num(u8),
+///     Add(Box<Expr>, Box<Expr>),
+/// };
+///
+/// rule! {
+///     expr = choice!(
+///         sequence!((term.clone()) > '+' > (expr.clone()) => |(a, (_, b))| Expr::Add(Box::new(a), Box::new(b))),
+///         term.clone()
+///     );
+///     term = pbind(pnum(), Expr::Num);
+/// }
+///
+/// match expr.parse("1+2".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(
+///         val,
+///         Expr::Add(Box::new(Expr::Num(b'1')), Box::new(Expr::Num(b'2')))
+///     ),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+#[macro_export]
+macro_rules! rule {
+    ( $( $name:ident = $body:expr ; )+ ) => {
+        $( let $name = $crate::parser::recursive::Recursive::declare(); )+
+        $( $name.define($body); )+
+    };
 }
 
 /// Macro for wraping expressions or literals, typically for inside other macros.
@@ -215,3 +301,80 @@ macro_rules! wrap {
         $crate::parser::just($ch)
     };
 }
+
+/// Macro asserting that a parser succeeds on an input with a given value (and,
+/// optionally, a given remainder).
+///
+/// Inspired by pest's `consumes_to!`, this replaces the
+/// `match ... Ok(PSuccess { val, rest }) => assert_eq!(...)` boilerplate seen
+/// throughout this crate's own doc examples with a single assertion.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+///
+/// parses_to!(pnum(), "1+2".into_input(), b'1', "+2");
+/// parses_to!(just('A'), "A".into_input(), b'A');
+/// ```
+#[macro_export]
+macro_rules! parses_to {
+    ($parser:expr, $input:expr, $val:expr) => {
+        match $parser.parse($input) {
+            Ok($crate::parser::PSuccess { val, rest: _ }) => assert_eq!(val, $val),
+            Err(e) => panic!("expected a successful parse, got {:?}", e),
+        }
+    };
+
+    // `$rest` is the *remaining content* (e.g. a `&str`/`&[u8]` literal), not an
+    // `Input` — `Input` generally carries the original buffer plus an absolute
+    // position, so two `Input`s with the same remaining content but different
+    // provenance aren't `==`. Comparing the remaining bytes is what the caller means.
+    ($parser:expr, $input:expr, $val:expr, $rest:expr) => {
+        match $parser.parse($input) {
+            Ok($crate::parser::PSuccess { val, rest }) => {
+                assert_eq!(val, $val);
+                assert_eq!(rest.as_bytes(), $rest.as_bytes());
+            }
+            Err(e) => panic!("expected a successful parse, got {:?}", e),
+        }
+    };
+}
+
+/// Macro asserting that a parser fails at a given offset, with a given `expected` set
+/// once merged via [`parser::error::PFailure::merge`](crate::parser::error::PFailure::merge).
+///
+/// Companion to [`parses_to!`]. Omit the `expected` set to only assert the offset.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+///
+/// fails_at!(just('A'), "B".into_input(), 0, {"'A'"});
+///
+/// // `just('A')` and `just('B')` both fail at offset 0, so `por`/`choice!` merges
+/// // their `expected` sets instead of reporting only the last branch tried.
+/// fails_at!(choice!(just('A'), just('B')), "C".into_input(), 0, {"'A'", "'B'"});
+/// ```
+#[macro_export]
+macro_rules! fails_at {
+    ($parser:expr, $input:expr, $offset:expr) => {
+        match $parser.parse($input) {
+            Ok(s) => panic!("expected a failed parse, got {:?}", s.val),
+            Err(e) => assert_eq!(e.position, $offset),
+        }
+    };
+
+    ($parser:expr, $input:expr, $offset:expr, { $($expected:expr),+ $(,)? }) => {
+        match $parser.parse($input) {
+            Ok(s) => panic!("expected a failed parse, got {:?}", s.val),
+            Err(e) => {
+                assert_eq!(e.position, $offset);
+                let expected: std::collections::BTreeSet<String> =
+                    [$($expected.to_string()),+].into_iter().collect();
+                assert_eq!(e.expected, expected);
+            }
+        }
+    };
+}
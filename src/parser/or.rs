@@ -0,0 +1,144 @@
+//! Combinators for trying one parser, then falling back to another.
+//!
+//! [`por`] is the two-parser primitive that [`choice!`](crate::choice!) expands into.
+//! [`Choice`] generalizes the same idea to tuples of parsers so that alternatives can
+//! be built up without macro expansion, e.g. when the set of alternatives is assembled
+//! dynamically or threaded through generic code.
+//!
+//! On failure, both [`por`] and [`Choice::parse_choice`] merge the branches' errors via
+//! [`PFailure::merge`](crate::parser::error::PFailure::merge) rather than discarding all
+//! but the last one tried, so a long `choice!`/`select!` chain reports every alternative
+//! that got equally far into the input.
+
+use crate::input::Input;
+use crate::parser::{PResult, Parser};
+
+/// Tries `p` against the input, and if it fails, tries `q` against the same input.
+///
+/// This is the primitive that [`choice!`](crate::choice!) recursively nests to support
+/// more than two alternatives. When both branches fail, the two
+/// [`PFailure`](crate::parser::error::PFailure)s are merged via
+/// [`PFailure::merge`](crate::parser::error::PFailure::merge): the one that got furthest into the input wins, and
+/// a tie merges their `expected` sets. This is what makes errors out of a deeply
+/// nested `choice!`/`select!` tree read as "expected one of {...}" rather than
+/// reporting only whichever alternative happened to be tried last.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::or::por;
+///
+/// let input = "B".into_input();
+/// let parser = por(just('A'), just('B'));
+///
+/// match parser.parse(input) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, b'B'),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+pub fn por<P, Q, O>(p: P, q: Q) -> impl Parser<O>
+where
+    P: Parser<O>,
+    Q: Parser<O>,
+{
+    move |input: Input| match p.parse(input.clone()) {
+        Ok(success) => Ok(success),
+        Err(p_err) => match q.parse(input) {
+            Ok(success) => Ok(success),
+            Err(q_err) => Err(p_err.merge(q_err)),
+        },
+    }
+}
+
+/// Tries a tuple of parsers left-to-right, returning the first success.
+///
+/// Implemented for tuples `(P1, P2)` through `(P1, ..., P8)`. Each impl threads the
+/// input through exactly as [`por`] does: on failure, the *original* input (not
+/// whatever was left over from the failed attempt) is handed to the next parser.
+///
+/// This is what [`choice`] delegates to. [`choice!`](crate::choice!) does not expand
+/// into a `Choice` tuple; it nests [`por`] calls, which gives the same results and the
+/// same merged errors. Unlike the macro, a `Choice` tuple is a single, fully-typed value, so it
+/// composes with generics and can be built up dynamically (e.g. behind a function that
+/// returns `impl Choice<O>`).
+pub trait Choice<O> {
+    /// Runs the alternatives in order, returning the first success.
+    fn parse_choice(&self, input: Input) -> PResult<O>;
+}
+
+/// Builds a parser out of a tuple of alternatives.
+///
+/// Every slot in the tuple must parse to the same output type `O`, just like every
+/// branch of a `choice!`/`select!` chain. Mix parsers with different native outputs
+/// (e.g. `just` and `pident`) by mapping each one into a common type first, with
+/// `.into_()`/`pbind`, as [`select!`](crate::select!) does.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::or::choice;
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Letter { A, B, Xyz }
+///
+/// let parser = choice((
+///     just('A').into_(Letter::A),
+///     just('B').into_(Letter::B),
+///     pident("xyz").into_(Letter::Xyz),
+/// ));
+///
+/// match parser.parse("xyz".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, Letter::Xyz),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+///
+/// This is equivalent to `choice!(just('A').into_(Letter::A), just('B').into_(Letter::B),
+/// pident("xyz").into_(Letter::Xyz))`, but the tuple itself is an ordinary value: it can
+/// be constructed dynamically, passed around, or built generically over `C: Choice<O>`.
+/// Each slot carries the same `Parser<O>` bound that [`por`] puts on its two arguments.
+pub fn choice<C, O>(choices: C) -> impl Parser<O>
+where
+    C: Choice<O>,
+{
+    move |input: Input| choices.parse_choice(input)
+}
+
+macro_rules! impl_choice {
+    ($($p:ident),+) => {
+        impl<O, $($p),+> Choice<O> for ($($p,)+)
+        where
+            $($p: Parser<O>),+
+        {
+            #[allow(non_snake_case)]
+            fn parse_choice(&self, input: Input) -> PResult<O> {
+                let ($($p,)+) = self;
+                impl_choice!(@try input, $($p),+)
+            }
+        }
+    };
+
+    (@try $input:ident, $last:ident) => {
+        $last.parse($input)
+    };
+
+    (@try $input:ident, $head:ident, $($tail:ident),+) => {
+        match $head.parse($input.clone()) {
+            Ok(success) => Ok(success),
+            Err(head_err) => match impl_choice!(@try $input, $($tail),+) {
+                Ok(success) => Ok(success),
+                Err(tail_err) => Err(head_err.merge(tail_err)),
+            },
+        }
+    };
+}
+
+impl_choice!(P1, P2);
+impl_choice!(P1, P2, P3);
+impl_choice!(P1, P2, P3, P4);
+impl_choice!(P1, P2, P3, P4, P5);
+impl_choice!(P1, P2, P3, P4, P5, P6);
+impl_choice!(P1, P2, P3, P4, P5, P6, P7);
+impl_choice!(P1, P2, P3, P4, P5, P6, P7, P8);
@@ -0,0 +1,83 @@
+//! Whitespace-skipping wrappers for leaf parsers.
+//!
+//! `sequence!`-built grammars choke on any stray whitespace between tokens unless
+//! every token is hand-wrapped in a space parser. Following synom's strategy, only
+//! *fundamental* parsers skip leading whitespace, via [`lexeme`]; composite parsers
+//! (`por`, `pseq`, `choice!`, ...) built from `lexeme`-wrapped leaves inherit correct
+//! whitespace handling for free, since those leaves already skip it. A leaf that is not
+//! wrapped (a bare `just('A')`) does not skip whitespace. [`padded`] is the lower-level combinator `lexeme`
+//! wraps; reach for it directly when you want to skip whitespace in front of something
+//! that isn't a leaf token.
+
+use crate::input::Input;
+use crate::parser::Parser;
+
+/// What counts as whitespace to skip over.
+///
+/// The default, [`AsciiWhitespace`], matches `Input`'s own byte-oriented view so that
+/// [`padded`]/[`lexeme`] work the same whether the input came from a `&str` or a
+/// `&[u8]`. Provide your own impl to skip a different set of bytes (e.g. only spaces
+/// and tabs); the test is made one byte at a time.
+pub trait Whitespace {
+    /// Returns true if `byte` should be skipped.
+    fn is_whitespace(byte: u8) -> bool;
+}
+
+/// Skips ASCII whitespace (space, tab, newline, form feed, carriage return).
+pub struct AsciiWhitespace;
+
+impl Whitespace for AsciiWhitespace {
+    fn is_whitespace(byte: u8) -> bool {
+        byte.is_ascii_whitespace()
+    }
+}
+
+/// Skips leading whitespace (as defined by `W`), then runs `p`.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::pad::padded;
+///
+/// let parser = padded::<_, _, cypress::parser::pad::AsciiWhitespace>(just('A'));
+///
+/// match parser.parse("   A".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, b'A'),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+pub fn padded<P, O, W>(p: P) -> impl Parser<O>
+where
+    P: Parser<O>,
+    W: Whitespace,
+{
+    move |input: Input| p.parse(input.skip_while(W::is_whitespace))
+}
+
+/// Wraps a leaf parser so it tolerates leading whitespace, using [`AsciiWhitespace`].
+///
+/// This is the wrapper `sequence!` applies to every `wrap!`-ed token, which is the key
+/// invariant that lets `sequence!((pnum()) > '+' > (pnum()))` skip spaces around `+`
+/// without a global `ws!`-style wrapper: only the leaves (`pnum()`, `just('+')`) skip
+/// whitespace, and every composite parser built out of them inherits that behavior.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::pad::lexeme;
+///
+/// let parser = lexeme(just('+'));
+///
+/// match parser.parse("  +".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, b'+'),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+pub fn lexeme<P, O>(p: P) -> impl Parser<O>
+where
+    P: Parser<O>,
+{
+    padded::<P, O, AsciiWhitespace>(p)
+}
@@ -0,0 +1,149 @@
+//! Error-recovery combinators that resynchronize instead of aborting the whole parse.
+//!
+//! Borrowed from chumsky: [`recover_with`] pairs a parser with a recovery
+//! [`Strategy`]. When the parser fails, the strategy resynchronizes the input (e.g.
+//! by discarding tokens via [`skip_until`]) and a caller-supplied `fallback` closure
+//! produces an error-node placeholder value, so parsing continues instead of bailing
+//! out. The failure itself isn't thrown away: it's pushed onto a [`Diagnostics`] sink
+//! shared across a whole grammar, so [`parse_recovering`] can hand back a value
+//! *and* the list of everything that went wrong, rather than just the first failure.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::input::Input;
+use crate::parser::error::PFailure;
+use crate::parser::{PResult, PSuccess, Parser};
+
+/// A side channel of recovered errors, shared by every [`recover_with`] site in a
+/// grammar.
+///
+/// Clone it freely: every clone records into the same underlying list. Build one with
+/// [`Diagnostics::new`], thread clones into each `recover_with` call, then read the
+/// accumulated errors back out with [`parse_recovering`].
+#[derive(Clone, Default)]
+pub struct Diagnostics {
+    errors: Rc<RefCell<Vec<PFailure>>>,
+}
+
+impl Diagnostics {
+    /// Creates an empty diagnostics sink.
+    pub fn new() -> Self {
+        Diagnostics::default()
+    }
+
+    fn record(&self, error: PFailure) {
+        self.errors.borrow_mut().push(error);
+    }
+
+    /// Drains every error recorded so far.
+    pub fn take(&self) -> Vec<PFailure> {
+        std::mem::take(&mut self.errors.borrow_mut())
+    }
+}
+
+/// How a [`recover_with`] parser resynchronizes after a failure.
+pub trait Strategy {
+    /// Consumes input until it's back at a point parsing can reasonably resume from.
+    fn resync(&self, input: Input) -> Input;
+}
+
+/// A [`Strategy`] that discards input up to (and including) the next match of `sync`.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::recover::{recover_with, skip_until, Diagnostics};
+///
+/// let diagnostics = Diagnostics::new();
+/// let parser = recover_with(just('A'), skip_until(just(';')), || b'?', diagnostics.clone());
+///
+/// match parser.parse("garbage;".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, b'?'),
+///     Err(_) => assert!(false),
+/// };
+/// assert_eq!(diagnostics.take().len(), 1);
+/// ```
+pub fn skip_until<S, U>(sync: S) -> impl Strategy
+where
+    S: Parser<U>,
+{
+    SkipUntil {
+        sync,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+struct SkipUntil<S, U> {
+    sync: S,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<S, U> Strategy for SkipUntil<S, U>
+where
+    S: Parser<U>,
+{
+    fn resync(&self, mut input: Input) -> Input {
+        loop {
+            if let Ok(success) = self.sync.parse(input.clone()) {
+                break success.rest;
+            }
+            match input.advance() {
+                Some(next) => input = next,
+                None => break input,
+            }
+        }
+    }
+}
+
+/// Runs `p`; on failure, records the error into `diagnostics`, resynchronizes the
+/// input via `strategy`, and returns `fallback()` as a placeholder value instead of
+/// propagating the error.
+///
+/// This is what lets a `sequence!`-built statement parser skip to the next `;` on a
+/// syntax error and keep producing a usable AST, which is essential for tooling use
+/// cases (editors, formatters) that need a full tree even over invalid input.
+pub fn recover_with<P, S, O>(
+    p: P,
+    strategy: S,
+    fallback: impl Fn() -> O,
+    diagnostics: Diagnostics,
+) -> impl Parser<O>
+where
+    P: Parser<O>,
+    S: Strategy,
+{
+    move |input: Input| match p.parse(input.clone()) {
+        Ok(success) => Ok(success),
+        Err(error) => {
+            diagnostics.record(error);
+            Ok(PSuccess {
+                val: fallback(),
+                rest: strategy.resync(input),
+            })
+        }
+    }
+}
+
+/// Runs `p` against `input`, returning its value alongside every error recorded into
+/// `diagnostics` so far by the `recover_with` sites that share it.
+///
+/// The parse itself is a plain `p.parse(input)`; the `recover_with` sites inside `p`
+/// are what turn sub-parser failures into fallback values. As long as `p` itself
+/// succeeds (which it will, once every failure point inside it is wrapped in
+/// `recover_with`), the diagnostics vector reports what was recovered from. If `p`
+/// fails, its error is returned and the recorded errors stay in `diagnostics`.
+pub fn parse_recovering<P, O>(
+    p: P,
+    input: Input,
+    diagnostics: Diagnostics,
+) -> PResult<(O, Vec<PFailure>)>
+where
+    P: Parser<O>,
+{
+    p.parse(input).map(|success| PSuccess {
+        val: (success.val, diagnostics.take()),
+        rest: success.rest,
+    })
+}
@@ -0,0 +1,62 @@
+//! Indirection for named, mutually-recursive parser definitions.
+//!
+//! Combinator values built out of `sequence!`/`choice!`/`select!` have anonymous
+//! (and often infinitely-sized, for a genuinely recursive grammar) types, so a
+//! parser can't refer to itself or to a sibling defined after it. [`Recursive`] breaks
+//! the cycle: a forward-declared handle can be cloned into place wherever it's needed,
+//! and its body is filled in once, after every handle in the group exists. This is the
+//! piece [`rule!`](crate::rule!) builds on to let `expr`/`term`-style mutually
+//! recursive grammars compile.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::input::Input;
+use crate::parser::error::PFailure;
+use crate::parser::{PResult, Parser};
+
+/// A parser that may refer to itself (or to siblings) before its body is known.
+///
+/// Clone a `Recursive` to get another handle to the same, eventually-defined parser;
+/// every clone shares the same underlying `Rc<RefCell<..>>`, so filling in the body
+/// once via [`Recursive::define`] makes it visible through every handle.
+pub struct Recursive<O> {
+    inner: Rc<RefCell<Option<Box<dyn Fn(Input) -> PResult<O>>>>>,
+}
+
+impl<O> Clone for Recursive<O> {
+    fn clone(&self) -> Self {
+        Recursive {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<O> Recursive<O> {
+    /// Creates an undefined handle. Parsing through it before [`define`](Self::define)
+    /// is called fails with a [`PFailure`](crate::parser::error::PFailure) rather than
+    /// panicking, since that lets an incompletely-defined `rule!` group surface through
+    /// the ordinary `PResult` path instead of aborting the whole program.
+    pub fn declare() -> Self {
+        Recursive {
+            inner: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Fills in the parser this handle stands for.
+    pub fn define<P>(&self, p: P)
+    where
+        P: Parser<O> + 'static,
+    {
+        *self.inner.borrow_mut() = Some(Box::new(move |input| p.parse(input)));
+    }
+}
+
+impl<O> Parser<O> for Recursive<O> {
+    fn parse(&self, input: Input) -> PResult<O> {
+        match self.inner.borrow().as_deref() {
+            Some(f) => f(input),
+            None => Err(PFailure::new(input.position(), "a defined rule!")),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Separated-list combinators: the `item (sep item)*` shape behind argument lists,
+//! array literals, and every other comma/semicolon-delimited grammar.
+//!
+//! [`many`](crate::parser::Parser::many) has no notion of a separator between
+//! elements, which makes it awkward for the single most common grammar shape. [`sep_by`]
+//! and [`sep_by1`] mirror syn's `parse_separated_nonempty`: they parse zero-or-more /
+//! one-or-more `item`s interleaved with `sep`, discard the separator values, and
+//! collect the items into a `Vec`.
+
+use crate::input::Input;
+use crate::parser::{PResult, PSuccess, Parser};
+
+/// Parses zero or more `item`s interleaved with `sep`, collecting the items.
+///
+/// If `trailing` is `true`, a final `sep` after the last item is consumed (and
+/// discarded) if present; if `false`, a trailing `sep` is left for the next parser.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::sep::sep_by;
+///
+/// let parser = sep_by(pnum(), just(','), false);
+///
+/// match parser.parse("1,2,3".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, vec![b'1', b'2', b'3']),
+///     Err(_) => assert!(false),
+/// };
+///
+/// match parser.parse("".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, Vec::<u8>::new()),
+///     Err(_) => assert!(false),
+/// };
+/// ```
+pub fn sep_by<P, S, O, U>(item: P, sep: S, trailing: bool) -> impl Parser<Vec<O>>
+where
+    P: Parser<O>,
+    S: Parser<U>,
+{
+    move |input: Input| match item.parse(input.clone()) {
+        Err(_) => Ok(PSuccess {
+            val: Vec::new(),
+            rest: input,
+        }),
+        Ok(first) => parse_rest(&item, &sep, trailing, first),
+    }
+}
+
+/// Parses one or more `item`s interleaved with `sep`, collecting the items.
+///
+/// Fails (without consuming input) if not even one `item` can be parsed. See
+/// [`sep_by`] for the meaning of `trailing`.
+///
+/// # Examples
+///
+/// ```
+/// use cypress::prelude::*;
+/// use cypress::parser::sep::sep_by1;
+///
+/// let parser = sep_by1(pnum(), just(','), true);
+///
+/// match parser.parse("1,2,3,".into_input()) {
+///     Ok(PSuccess { val, rest: _ }) => assert_eq!(val, vec![b'1', b'2', b'3']),
+///     Err(_) => assert!(false),
+/// };
+///
+/// assert!(parser.parse("".into_input()).is_err());
+/// ```
+pub fn sep_by1<P, S, O, U>(item: P, sep: S, trailing: bool) -> impl Parser<Vec<O>>
+where
+    P: Parser<O>,
+    S: Parser<U>,
+{
+    move |input: Input| {
+        let first = item.parse(input)?;
+        parse_rest(&item, &sep, trailing, first)
+    }
+}
+
+fn parse_rest<P, S, O, U>(item: &P, sep: &S, trailing: bool, first: PSuccess<O>) -> PResult<Vec<O>>
+where
+    P: Parser<O>,
+    S: Parser<U>,
+{
+    let mut items = vec![first.val];
+    let mut rest = first.rest;
+
+    loop {
+        match sep.parse(rest.clone()) {
+            Err(_) => break,
+            Ok(after_sep) => match item.parse(after_sep.rest.clone()) {
+                Err(_) => {
+                    if trailing {
+                        rest = after_sep.rest;
+                    }
+                    break;
+                }
+                Ok(next) => {
+                    items.push(next.val);
+                    rest = next.rest;
+                }
+            },
+        }
+    }
+
+    Ok(PSuccess { val: items, rest })
+}